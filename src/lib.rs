@@ -11,39 +11,62 @@
 //! a byte with the highest bit set is read or if the underlying Rust primitive
 //! overflows.
 //!
+//! With the default `std` feature disabled, this crate is `no_std`: the
+//! [`std::io::Read`]/[`std::io::Write`]-based functions and the
+//! [`ReadMsb128Ext`]/[`WriteMsb128Ext`] traits are unavailable, and only the
+//! allocation-free [`encode`]/[`encoded_len`] and [`decode`] functions remain,
+//! working on caller-provided buffers instead of streams.
+//!
+
+#![cfg_attr(not(feature = "std"), no_std)]
 
 extern crate num_traits;
 
-use std::fmt;
+use core::fmt;
+#[cfg(feature = "std")]
 use std::io;
 
 /// An error type for reading MSB128 encoded integers.
 #[derive(Debug)]
 pub enum ReadError {
     /// IO Error while reading.
+    #[cfg(feature = "std")]
     IoError(io::Error),
     /// Encoded integer overflowed the expected integer.
     Overflow,
+    /// More than the caller-supplied maximum number of bytes were consumed
+    /// without encountering a terminating byte.
+    TooLong,
+    /// The buffer passed to [`decode`] ended before a terminating byte was
+    /// found.
+    Truncated,
 }
 
 impl fmt::Display for ReadError {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         match *self {
+            #[cfg(feature = "std")]
             ReadError::IoError(ref e) => e.fmt(f),
             ReadError::Overflow => write!(f, "encoded integer overflows the type"),
+            ReadError::TooLong => write!(f, "encoded integer exceeds the maximum allowed length"),
+            ReadError::Truncated => write!(f, "buffer ended before a terminating byte was found"),
         }
     }
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for ReadError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match *self {
             ReadError::IoError(ref e) => Some(e),
             ReadError::Overflow => None,
+            ReadError::TooLong => None,
+            ReadError::Truncated => None,
         }
     }
 }
 
+#[cfg(feature = "std")]
 impl From<io::Error> for ReadError {
     fn from(e: io::Error) -> Self {
         ReadError::IoError(e)
@@ -114,16 +137,37 @@ impl From<io::Error> for ReadError {
 /// # }
 ///
 /// ```
-pub fn read_positive<R, I>(mut reader: R) -> Result<I, ReadError>
+#[cfg(feature = "std")]
+pub fn read_positive<R, I>(reader: R) -> Result<I, ReadError>
+where
+    R: io::Read,
+    I: num_traits::PrimInt,
+{
+    read_positive_counted(reader).map(|(number, _)| number)
+}
+
+/// The shared decode loop backing [`read_positive_counted`] and
+/// [`read_positive_limited`], optionally bailing out with
+/// [`ReadError::TooLong`] instead of reading past `max_bytes` bytes when
+/// none of them was a terminating byte.
+#[cfg(feature = "std")]
+fn read_positive_core<R, I>(mut reader: R, max_bytes: Option<usize>) -> Result<(I, usize), ReadError>
 where
     R: io::Read,
     I: num_traits::PrimInt,
 {
     let mut number: I = I::zero();
     let mut buf = [0];
+    let mut consumed = 0;
     loop {
+        if let Some(max_bytes) = max_bytes {
+            if consumed >= max_bytes {
+                return Err(ReadError::TooLong);
+            }
+        }
         // read the next byte from r into the buffer
         reader.read_exact(&mut buf)?;
+        consumed += 1;
         let buffer_value: u8 = buf[0];
         // append the last 127 bits of the buffer to the number
         // (if it wouldn't overflow while doing so)
@@ -140,11 +184,12 @@ where
             }
             number = number + I::one();
         } else {
-            return Ok(number);
+            return Ok((number, consumed));
         }
     }
 }
 
+#[cfg(feature = "std")]
 #[test]
 fn test_reading() {
     assert_eq!(0, read_positive(&mut &[0x00][..]).unwrap());
@@ -163,10 +208,198 @@ fn test_reading() {
     );
 }
 
+/// Read a variable length and MSB128-encoded integer from `r`, like
+/// [`read_positive`], and additionally return the number of bytes
+/// consumed from `r`.
+///
+/// This is useful to track offsets when decoding packed records, where
+/// several MSB128-encoded integers follow each other in the same stream.
+///
+/// # Examples
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use msb128::read_positive_counted;
+///
+/// let data = [0x81, 0x00, 0x7F];
+/// let mut readable = &data[..];
+///
+/// assert_eq!((256u32, 2), read_positive_counted(&mut readable)?);
+/// assert_eq!((127u32, 1), read_positive_counted(&mut readable)?);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "std")]
+pub fn read_positive_counted<R, I>(reader: R) -> Result<(I, usize), ReadError>
+where
+    R: io::Read,
+    I: num_traits::PrimInt,
+{
+    read_positive_core(reader, None)
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_reading_counted() {
+    assert_eq!((0u32, 1), read_positive_counted(&mut &[0x00][..]).unwrap());
+    assert_eq!(
+        (256u32, 2),
+        read_positive_counted(&mut &[0x81, 0x00][..]).unwrap()
+    );
+    assert_eq!(
+        (1u64 << 32, 5),
+        read_positive_counted(&mut &[0x8E, 0xFE, 0xFE, 0xFF, 0x00][..]).unwrap()
+    );
+}
+
+/// Read a variable length and MSB128-encoded integer from `r`, like
+/// [`read_positive`], but bail out with [`ReadError::TooLong`] instead of
+/// reading past `max_bytes` bytes when none of them was a terminating
+/// byte.
+///
+/// A stream of bytes that all have the continuation bit set makes
+/// [`read_positive`] loop until the target type `I` overflows, which for
+/// large types like `u128` is up to 20 reads; `read_positive_limited`
+/// bounds this independently of `I`'s width, which is useful when
+/// decoding attacker-controlled data.
+///
+/// # Errors
+///
+/// Same as [`read_positive`], plus a [`ReadError::TooLong`][1] once
+/// `max_bytes` bytes have been read without a terminating byte among them.
+///
+/// [1]: enum.ReadError.html#variant.TooLong
+///
+/// # Examples
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use msb128::{read_positive_limited, ReadError};
+///
+/// let data = [0x81, 0x00];
+/// let mut readable = &data[..];
+/// assert_eq!(256u32, read_positive_limited(&mut readable, 2)?);
+///
+/// let mut readable = &data[..];
+/// assert!(matches!(
+///     read_positive_limited::<_, u32>(&mut readable, 1),
+///     Err(ReadError::TooLong)
+/// ));
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "std")]
+pub fn read_positive_limited<R, I>(reader: R, max_bytes: usize) -> Result<I, ReadError>
+where
+    R: io::Read,
+    I: num_traits::PrimInt,
+{
+    read_positive_core(reader, Some(max_bytes)).map(|(number, _)| number)
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_reading_limited() {
+    assert_eq!(
+        256u32,
+        read_positive_limited(&mut &[0x81, 0x00][..], 2).unwrap()
+    );
+    assert!(matches!(
+        read_positive_limited::<_, u32>(&mut &[0x81, 0x00][..], 1),
+        Err(ReadError::TooLong)
+    ));
+    // a never-ending stream of continuation bytes is bounded by max_bytes,
+    // not by I's width
+    let forever = [0x80u8; 64];
+    assert!(matches!(
+        read_positive_limited::<_, u128>(&mut &forever[..], 10),
+        Err(ReadError::TooLong)
+    ));
+}
+
+/// Decode one MSB128-encoded, positive integer from the front of `buf`,
+/// returning it together with the unconsumed tail of `buf`.
+///
+/// Unlike [`read_positive`] and [`read_positive_counted`], this walks
+/// `buf` directly instead of going through [`std::io::Read`], so it is
+/// available without the `std` feature, and the returned slice borrows
+/// from `buf` instead of copying. This is convenient when decoding
+/// several packed integers out of a byte slice one after another.
+///
+/// # Errors
+///
+/// Same as [`read_positive`], plus a [`ReadError::Truncated`][1] if `buf`
+/// ends before a terminating byte is found.
+///
+/// [1]: enum.ReadError.html#variant.Truncated
+///
+/// # Examples
+///
+/// ```
+/// use msb128::decode;
+///
+/// let data = [0x81, 0x00, 0x7F];
+///
+/// let (val, rest) = decode::<u32>(&data).unwrap();
+/// assert_eq!(256, val);
+///
+/// let (val, rest) = decode::<u32>(rest).unwrap();
+/// assert_eq!(127, val);
+/// assert!(rest.is_empty());
+/// ```
+pub fn decode<I>(buf: &[u8]) -> Result<(I, &[u8]), ReadError>
+where
+    I: num_traits::PrimInt,
+{
+    let mut number: I = I::zero();
+    let mut pos = 0;
+    loop {
+        let buffer_value = *buf.get(pos).ok_or(ReadError::Truncated)?;
+        pos += 1;
+        if number > I::max_value() >> 7 {
+            return Err(ReadError::Overflow);
+        }
+        number = (number << 7) | I::from(buffer_value & 0x7F).unwrap();
+        if buffer_value & 0x80 > 0 {
+            if number == I::max_value() {
+                return Err(ReadError::Overflow);
+            }
+            number = number + I::one();
+        } else {
+            return Ok((number, &buf[pos..]));
+        }
+    }
+}
+
+#[test]
+fn test_decode() {
+    let data = [0x81, 0x00, 0x7F];
+
+    let (val, rest) = decode::<u32>(&data).unwrap();
+    assert_eq!(256, val);
+    assert_eq!(&[0x7F], rest);
+
+    let (val, rest) = decode::<u32>(rest).unwrap();
+    assert_eq!(127, val);
+    assert!(rest.is_empty());
+}
+
+#[test]
+fn test_decode_truncated() {
+    assert!(matches!(
+        decode::<u32>(&[0x81]),
+        Err(ReadError::Truncated)
+    ));
+    assert!(matches!(decode::<u32>(&[]), Err(ReadError::Truncated)));
+}
+
 /// An error type for writing MSB128 encoded integers.
 #[derive(Debug)]
 pub enum WriteError {
     /// IO Error while writing.
+    #[cfg(feature = "std")]
     IoError(io::Error),
     /// Passed integer is negative. Only positive (but both signed or unsigned)
     /// are allowed.
@@ -176,12 +409,14 @@ pub enum WriteError {
 impl fmt::Display for WriteError {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
         match *self {
+            #[cfg(feature = "std")]
             WriteError::IoError(ref e) => e.fmt(f),
             WriteError::Negative => write!(f, "writing a negative integer is unsupported"),
         }
     }
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for WriteError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match *self {
@@ -191,12 +426,121 @@ impl std::error::Error for WriteError {
     }
 }
 
+#[cfg(feature = "std")]
 impl From<io::Error> for WriteError {
     fn from(e: io::Error) -> Self {
         WriteError::IoError(e)
     }
 }
 
+/// The maximum number of bytes [`encode`] ever needs: the MSB128 encoding
+/// of the largest supported (128 bit) integer.
+const MAX_ENCODED_LEN: usize = 19;
+
+/// The number of bytes [`encode`] would write for `val`, without writing
+/// anything. Useful to size a buffer up front.
+///
+/// # Examples
+///
+/// ```
+/// use msb128::encoded_len;
+///
+/// assert_eq!(1, encoded_len(127u32));
+/// assert_eq!(2, encoded_len(128u32));
+/// ```
+pub fn encoded_len<I>(val: I) -> usize
+where
+    I: num_traits::PrimInt,
+{
+    let mut val = val;
+    let mut len = 1;
+    while val > I::from(0x7Fu8).unwrap() {
+        val = (val >> 7) - I::one();
+        len += 1;
+    }
+    len
+}
+
+/// Encode `val` as an MSB128-encoded integer into `out`, returning the
+/// number of bytes written. `out` must be at least
+/// [`encoded_len`]`(val)` bytes long.
+///
+/// Unlike [`write_positive`], this does not go through [`std::io::Write`]
+/// and never allocates: it is available without the `std` feature.
+///
+/// # Errors
+/// Only positive integers are supported. A negative input causes the
+/// function to return with a [`WriteError::Negative`][1].
+///
+/// [1]: enum.WriteError.html#variant.Negative
+///
+/// # Examples
+///
+/// ```
+/// use msb128::encode;
+///
+/// let mut buf = [0u8; 2];
+/// assert_eq!(2, encode(256u32, &mut buf).unwrap());
+/// assert_eq!([0x81, 0x00], buf);
+/// ```
+pub fn encode<I>(val: I, out: &mut [u8]) -> Result<usize, WriteError>
+where
+    I: num_traits::PrimInt,
+{
+    // dont allow writing of negative values
+    if val < I::zero() {
+        return Err(WriteError::Negative);
+    }
+    // filled from the end, since the number of bytes needed is only known
+    // once the most significant byte is reached
+    let mut buf = [0u8; MAX_ENCODED_LEN];
+    let mut val = val;
+    let mut pos = MAX_ENCODED_LEN;
+    let mut more = false;
+    loop {
+        pos -= 1;
+        buf[pos] = (val & I::from(0x7Fu8).unwrap()).to_u8().unwrap()
+            | if more { 0x80 } else { 0x00 };
+        if val <= I::from(0x7Fu8).unwrap() {
+            break;
+        }
+        val = (val >> 7) - I::one();
+        more = true;
+    }
+    let len = MAX_ENCODED_LEN - pos;
+    out[..len].copy_from_slice(&buf[pos..]);
+    Ok(len)
+}
+
+#[test]
+fn test_encode() {
+    let testcases = [
+        (0, 1, [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+        (1, 1, [0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+        (127, 1, [0x7F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+        (128, 2, [0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+        (256, 2, [0x81, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+        (
+            1i64 << 32,
+            5,
+            [0x8E, 0xFE, 0xFE, 0xFF, 0x00, 0x00, 0x00, 0x00],
+        ),
+    ];
+
+    for tc in testcases {
+        let mut buf = [0u8; 8];
+        assert_eq!(tc.1, encoded_len(tc.0));
+        assert_eq!(tc.1, encode(tc.0, &mut buf).unwrap());
+        assert_eq!(tc.2, buf);
+    }
+}
+
+#[test]
+fn test_encode_is_err_on_negative() {
+    let mut buf = [0u8; 8];
+    assert!(encode(-2, &mut buf).is_err());
+}
+
 /// Write `val` to the `std::io::Write` stream `w` as an MSB128-encoded
 /// integer.
 ///
@@ -234,37 +578,19 @@ impl From<io::Error> for WriteError {
 /// # Ok(())
 /// }
 /// ```
+#[cfg(feature = "std")]
 pub fn write_positive<W, I>(mut writer: W, input: I) -> Result<usize, WriteError>
 where
     W: io::Write,
     I: num_traits::PrimInt,
 {
-    // dont allow writing of negative values
-    if input < I::zero() {
-        return Err(WriteError::Negative);
-    }
-    let mut val = input.clone();
-    let mut tmp = std::vec::Vec::new();
-    let mut index = 0;
-    loop {
-        let b = (val & I::from(0x7Fu8).unwrap())
-            | (if index > 0 {
-                I::from(0x80).unwrap()
-            } else {
-                I::zero()
-            });
-        tmp.push(b.to_u8().unwrap());
-        if val <= I::from(0x7Fu8).unwrap() {
-            break;
-        }
-        val = (val >> 7) - I::one();
-        index += 1;
-    }
-    tmp.reverse();
-    writer.write_all(tmp.as_slice())?;
-    Ok(tmp.len())
+    let mut buf = [0u8; MAX_ENCODED_LEN];
+    let len = encode(input, &mut buf)?;
+    writer.write_all(&buf[..len])?;
+    Ok(len)
 }
 
+#[cfg(feature = "std")]
 #[test]
 fn test_writing() {
     let testcases = vec![
@@ -294,6 +620,7 @@ fn test_writing() {
     }
 }
 
+#[cfg(feature = "std")]
 #[test]
 fn test_write_and_then_read() {
     let mut buf = [0u8; 4096];
@@ -319,9 +646,361 @@ fn test_write_and_then_read() {
     }
 }
 
+#[cfg(feature = "std")]
 #[test]
 fn test_is_err_on_negative_write() {
     let mut buf = [0u8; 8];
     let mut writable = &mut buf[..];
     assert!(write_positive(&mut writable, -2).is_err());
 }
+
+/// Like [`read_positive`]/[`write_positive`], but reading or writing the
+/// raw bit pattern of `I` instead of a non-negative value, by shifting and
+/// overflow-checking across `I`'s full bit width instead of stopping at
+/// `I::max_value()`.
+///
+/// [`read_signed`]'s zig-zag mapped `u` can set `I`'s sign bit even though
+/// it represents a magnitude up to `2 * I::max_value() + 1`, so it cannot be
+/// read with [`read_positive`] (which treats a set sign bit as overflow)
+/// nor written with [`write_positive`] (which rejects it as negative).
+/// `I::unsigned_shr` and wrapping addition keep the bit-shifting and the
+/// "add 1 before a continuation byte" step correct regardless of how the
+/// bit pattern would compare or add as a signed `I`.
+#[cfg(feature = "std")]
+fn read_bits<R, I>(mut reader: R) -> Result<I, ReadError>
+where
+    R: io::Read,
+    I: num_traits::PrimInt + num_traits::WrappingAdd,
+{
+    let bits = I::zero().count_zeros();
+    let mut number: I = I::zero();
+    let mut buf = [0];
+    loop {
+        reader.read_exact(&mut buf)?;
+        let buffer_value: u8 = buf[0];
+        if number.unsigned_shr(bits - 7) != I::zero() {
+            return Err(ReadError::Overflow);
+        }
+        number = (number << 7) | I::from(buffer_value & 0x7F).unwrap();
+        if buffer_value & 0x80 > 0 {
+            if number == !I::zero() {
+                return Err(ReadError::Overflow);
+            }
+            number = number.wrapping_add(&I::one());
+        } else {
+            return Ok(number);
+        }
+    }
+}
+
+/// See [`read_bits`].
+#[cfg(feature = "std")]
+fn write_bits<I>(mut val: I, out: &mut [u8]) -> usize
+where
+    I: num_traits::PrimInt + num_traits::WrappingAdd,
+{
+    let mut buf = [0u8; MAX_ENCODED_LEN];
+    let mut pos = MAX_ENCODED_LEN;
+    let mut more = false;
+    loop {
+        pos -= 1;
+        buf[pos] = (val & I::from(0x7Fu8).unwrap()).to_u8().unwrap()
+            | if more { 0x80 } else { 0x00 };
+        if val.unsigned_shr(7) == I::zero() {
+            break;
+        }
+        val = val.unsigned_shr(7).wrapping_add(&!I::zero());
+        more = true;
+    }
+    let len = MAX_ENCODED_LEN - pos;
+    out[..len].copy_from_slice(&buf[pos..]);
+    len
+}
+
+/// Read a variable length and MSB128-encoded integer from `r`, allowing
+/// negative values via zig-zag mapping.
+///
+/// The value on the wire is the zig-zag mapped, always-positive `u`
+/// produced by [`write_signed`]. It is decoded like [`read_positive`],
+/// across the full bit width of `I` (`u` can need one more bit than
+/// `I::max_value()` provides), and then unmapped back to the signed value
+/// `n` via `n = (u >> 1) ^ -(u & 1)`, which recovers `0, -1, 1, -2, 2, …`
+/// from `u = 0, 1, 2, 3, 4, …` so small-magnitude negative numbers stay
+/// short.
+///
+/// # Errors
+///
+/// A [`ReadError::Overflow`][1] is returned if the zig-zag mapped value
+/// does not fit in `I`'s bit width.
+///
+/// [1]: enum.ReadError.html#variant.Overflow
+///
+/// # Examples
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use msb128::read_signed;
+///
+/// // -1, 1, -2
+/// let data = [0x01, 0x02, 0x03];
+/// let mut readable = &data[..];
+///
+/// assert_eq!(-1i32, read_signed(&mut readable)?);
+/// assert_eq!(1i32, read_signed(&mut readable)?);
+/// assert_eq!(-2i32, read_signed(&mut readable)?);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "std")]
+pub fn read_signed<R, I>(reader: R) -> Result<I, ReadError>
+where
+    R: io::Read,
+    I: num_traits::PrimInt + num_traits::Signed + num_traits::WrappingAdd,
+{
+    let u: I = read_bits(reader)?;
+    let sign = I::zero() - (u & I::one());
+    Ok(u.unsigned_shr(1) ^ sign)
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_reading_signed() {
+    assert_eq!(0, read_signed(&mut &[0x00][..]).unwrap());
+    assert_eq!(-1, read_signed(&mut &[0x01][..]).unwrap());
+    assert_eq!(1, read_signed(&mut &[0x02][..]).unwrap());
+    assert_eq!(-2, read_signed(&mut &[0x03][..]).unwrap());
+    assert_eq!(2, read_signed(&mut &[0x04][..]).unwrap());
+    let val: i32 = read_signed(&mut &[0x80, 0x7F][..]).unwrap();
+    assert_eq!(-128, val);
+}
+
+/// Write `val` to the `std::io::Write` stream `w` as an MSB128-encoded
+/// integer, allowing negative values via zig-zag mapping.
+///
+/// `val` is first mapped to an always-positive `u = (val << 1) ^ (val >>
+/// (BITS - 1))`, where `BITS` is the bit width of `I` and the right shift
+/// is the arithmetic (sign-propagating) shift of `I`. This maps `0, -1, 1,
+/// -2, 2, …` to `0, 1, 2, 3, 4, …`, so small-magnitude negative numbers
+/// stay short. `u` is then written like [`write_positive`], across the
+/// full bit width of `I` since `u` can need one more bit than
+/// `I::max_value()` provides.
+///
+/// # Returns
+/// After a successful write, the number of bytes written to `w` is returned.
+///
+/// # Examples
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use msb128::{write_signed, read_signed};
+///
+/// let mut buffer = [0u8; 2];
+/// let mut writeable = &mut buffer[..];
+///
+/// let bytes_written = write_signed(&mut writeable, -1i32)?;
+/// assert_eq!(bytes_written, 1);
+///
+/// let mut readable = &buffer[..];
+/// assert_eq!(-1i32, read_signed(&mut readable)?);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "std")]
+pub fn write_signed<W, I>(mut writer: W, input: I) -> Result<usize, WriteError>
+where
+    W: io::Write,
+    I: num_traits::PrimInt + num_traits::Signed + num_traits::WrappingAdd,
+{
+    let bits = I::zero().count_zeros() as usize;
+    let u = (input << 1) ^ (input >> (bits - 1));
+    let mut buf = [0u8; MAX_ENCODED_LEN];
+    let len = write_bits(u, &mut buf);
+    writer.write_all(&buf[..len])?;
+    Ok(len)
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_writing_signed() {
+    let testcases = vec![
+        (0i8, 1usize, [0x00u8, 0x00]),
+        (-1, 1, [0x01, 0x00]),
+        (1, 1, [0x02, 0x00]),
+        (-2, 1, [0x03, 0x00]),
+        (2, 1, [0x04, 0x00]),
+        // -64 is the last value whose zig-zag mapping (127) still fits in
+        // one byte's 7 magnitude bits
+        (-64, 1, [0x7F, 0x00]),
+        // from 64 (mapped to 128) and -65 on, the zig-zag mapped value sets
+        // i8's sign bit, which must not be mistaken for a negative value or
+        // an overflow
+        (64, 2, [0x80, 0x00]),
+        (-65, 2, [0x80, 0x01]),
+        (65, 2, [0x80, 0x02]),
+        (i8::MAX, 2, [0x80, 0x7E]),
+        (i8::MIN, 2, [0x80, 0x7F]),
+    ];
+
+    for tc in testcases {
+        let mut buf = [0u8; 2];
+        assert_eq!(tc.1, write_signed(&mut buf[..], tc.0).unwrap());
+        assert_eq!(tc.2, buf);
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_write_and_then_read_signed() {
+    let mut buf = [0u8; 4096];
+
+    let mut testcases = vec![];
+    for i in 2..63 {
+        testcases.push(-(1i64 << i) - 1);
+        testcases.push(-(1i64 << i));
+        testcases.push((1i64 << i) - 1);
+        testcases.push(1i64 << i);
+    }
+    // the magnitudes closest to I's bit width, where the zig-zag mapped
+    // value sets I's sign bit
+    testcases.push(i64::MIN);
+    testcases.push(i64::MIN + 1);
+    testcases.push(i64::MAX - 1);
+    testcases.push(i64::MAX);
+
+    let mut writable = &mut buf[..];
+    for tc in testcases.clone() {
+        write_signed(&mut writable, tc).unwrap();
+    }
+
+    let mut readable = &buf[..];
+    for tc in testcases {
+        let val: i64 = read_signed(&mut readable).unwrap();
+        assert_eq!(tc, val);
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_write_and_then_read_signed_i8_exhaustive() {
+    let mut buf = [0u8; 4096];
+
+    let mut writable = &mut buf[..];
+    for n in i8::MIN..=i8::MAX {
+        write_signed(&mut writable, n).unwrap();
+    }
+
+    let mut readable = &buf[..];
+    for n in i8::MIN..=i8::MAX {
+        let val: i8 = read_signed(&mut readable).unwrap();
+        assert_eq!(n, val);
+    }
+}
+
+/// An extension trait adding MSB128 decoding methods to all types
+/// implementing [`std::io::Read`], following the same ergonomic pattern as
+/// the `byteorder` crate's `ReadBytesExt`.
+///
+/// This lets an MSB128 field be read alongside other fields of a larger
+/// parser without importing the free functions separately.
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use msb128::ReadMsb128Ext;
+///
+/// let data = [0x81, 0x00];
+/// let mut readable = &data[..];
+///
+/// assert_eq!(256u32, readable.read_msb128::<u32>()?);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "std")]
+pub trait ReadMsb128Ext: io::Read {
+    /// Reads an MSB128-encoded, positive integer. See [`read_positive`].
+    fn read_msb128<I>(&mut self) -> Result<I, ReadError>
+    where
+        I: num_traits::PrimInt,
+    {
+        read_positive(self)
+    }
+
+    /// Reads an MSB128-encoded, zig-zag mapped signed integer. See
+    /// [`read_signed`].
+    fn read_msb128_signed<I>(&mut self) -> Result<I, ReadError>
+    where
+        I: num_traits::PrimInt + num_traits::Signed + num_traits::WrappingAdd,
+    {
+        read_signed(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: io::Read + ?Sized> ReadMsb128Ext for R {}
+
+/// An extension trait adding MSB128 encoding methods to all types
+/// implementing [`std::io::Write`], following the same ergonomic pattern as
+/// the `byteorder` crate's `WriteBytesExt`.
+///
+/// ```
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// use msb128::WriteMsb128Ext;
+///
+/// let mut buffer = [0u8; 2];
+/// let mut writeable = &mut buffer[..];
+///
+/// assert_eq!(2, writeable.write_msb128(256u64)?);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "std")]
+pub trait WriteMsb128Ext: io::Write {
+    /// Writes `val` as an MSB128-encoded, positive integer. See
+    /// [`write_positive`].
+    fn write_msb128<I>(&mut self, val: I) -> Result<usize, WriteError>
+    where
+        I: num_traits::PrimInt,
+    {
+        write_positive(self, val)
+    }
+
+    /// Writes `val` as an MSB128-encoded, zig-zag mapped signed integer.
+    /// See [`write_signed`].
+    fn write_msb128_signed<I>(&mut self, val: I) -> Result<usize, WriteError>
+    where
+        I: num_traits::PrimInt + num_traits::Signed + num_traits::WrappingAdd,
+    {
+        write_signed(self, val)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: io::Write + ?Sized> WriteMsb128Ext for W {}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_read_msb128_ext() {
+    let data = [0x0D, 0x81, 0x00, 0x01];
+    let mut readable = &data[..];
+
+    assert_eq!(0x0Du8, readable.read_msb128().unwrap());
+    assert_eq!(256u32, readable.read_msb128().unwrap());
+    assert_eq!(-1i32, readable.read_msb128_signed().unwrap());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_write_msb128_ext() {
+    let mut buf = [0u8; 8];
+    let mut writeable = &mut buf[..];
+
+    assert_eq!(2, writeable.write_msb128(256u64).unwrap());
+    assert_eq!(1, writeable.write_msb128_signed(-1i32).unwrap());
+
+    let mut readable = &buf[..];
+    assert_eq!(256u64, readable.read_msb128().unwrap());
+    assert_eq!(-1i32, readable.read_msb128_signed().unwrap());
+}